@@ -11,7 +11,7 @@ use std::prelude::v1::*;
 use serde_derive::{Deserialize, Serialize};
 
 /// Node of the binary tree.
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct BinaryTreeNode<T> {
     /// Store information in a node.
     pub value: T,
@@ -24,6 +24,12 @@ pub struct BinaryTreeNode<T> {
 
     /// The index of the right child node. 0 means of right child.
     right: usize, // bigger than 0
+
+    /// The index of the parent node. The root stores 0 here, and so do the
+    /// root's own children, since the root sits at index 0. Older
+    /// serialized trees predate this field, so it defaults to 0 on load.
+    #[serde(default)]
+    parent: usize,
 }
 
 impl<T> BinaryTreeNode<T> {
@@ -42,8 +48,31 @@ impl<T> BinaryTreeNode<T> {
             index: 0,
             left: 0,
             right: 0,
+            parent: 0,
         }
     }
+
+    /// Return the index of this node within its [`BinaryTree`], as used by
+    /// [`BinaryTree::get_node`], [`BinaryTree::get_parent`],
+    /// [`BinaryTree::path_to_root`] and [`BinaryTree::remove_subtree`].
+    ///
+    /// [`BinaryTree`]: struct.BinaryTree.html
+    /// [`BinaryTree::get_node`]: struct.BinaryTree.html#method.get_node
+    /// [`BinaryTree::get_parent`]: struct.BinaryTree.html#method.get_parent
+    /// [`BinaryTree::path_to_root`]: struct.BinaryTree.html#method.path_to_root
+    /// [`BinaryTree::remove_subtree`]: struct.BinaryTree.html#method.remove_subtree
+    /// # Example
+    /// ```
+    /// use gbdt::binary_tree::{BinaryTree, BinaryTreeNode};
+    /// let mut tree: BinaryTree<i32> = BinaryTree::new();
+    /// let root_index = tree.add_root(BinaryTreeNode::new(1));
+    /// let left_index = tree.add_left_node(root_index, BinaryTreeNode::new(2));
+    /// let left_node = tree.get_node(left_index).unwrap();
+    /// assert_eq!(left_node.index(), left_index);
+    /// ```
+    pub fn index(&self) -> TreeIndex {
+        self.index
+    }
 }
 
 /// The index to retrive the tree node. Always get the index value from [`BinaryTree`] APIs.
@@ -56,6 +85,19 @@ pub type TreeIndex = usize;
 #[derive(Debug, Serialize, Deserialize)]
 pub struct BinaryTree<T> {
     tree: Vec<BinaryTreeNode<T>>,
+
+    /// Indices reclaimed by [`remove_subtree`] that can be reused by `add_node`
+    /// instead of growing `tree`. Index 0 (the root / "no child" sentinel) is
+    /// never placed here.
+    ///
+    /// Serialized and restored like any other field, so a tree that is
+    /// fragmented at the time it's saved stays reusable after a plain
+    /// `serde` round-trip; `#[serde(default)]` only covers data saved before
+    /// this field existed.
+    ///
+    /// [`remove_subtree`]: #method.remove_subtree
+    #[serde(default)]
+    free: Vec<TreeIndex>,
 }
 
 impl<T> Default for BinaryTree<T> {
@@ -67,12 +109,15 @@ impl<T> Default for BinaryTree<T> {
 impl<T> BinaryTree<T> {
     /// Build a new empty binary tree
     pub fn new() -> Self {
-        BinaryTree { tree: Vec::new() }
+        BinaryTree {
+            tree: Vec::new(),
+            free: Vec::new(),
+        }
     }
 
     /// Returns true when the binary tree is empty
     pub fn is_empty(&self) -> bool {
-        self.tree.is_empty()
+        self.len() == 0
     }
 
     /// Add a node as the root node. Return the index of the root node.
@@ -183,6 +228,52 @@ impl<T> BinaryTree<T> {
         self.tree.get_mut(index)
     }
 
+    /// Return the parent of the given `node`, or `None` if `node` is the root.
+    /// # Example
+    /// ```
+    /// use gbdt::binary_tree::{BinaryTree, BinaryTreeNode};
+    /// let mut tree: BinaryTree<i32> = BinaryTree::new();
+    /// let root_index = tree.add_root(BinaryTreeNode::new(1));
+    /// let left_index = tree.add_left_node(root_index, BinaryTreeNode::new(2));
+    /// let left_node = tree.get_node(left_index).unwrap();
+    /// let parent = tree.get_parent(left_node).unwrap();
+    /// assert_eq!(parent.value, 1);
+    /// ```
+    pub fn get_parent(&self, node: &BinaryTreeNode<T>) -> Option<&BinaryTreeNode<T>> {
+        if node.index == self.get_root_index() {
+            None
+        } else {
+            self.tree.get(node.parent)
+        }
+    }
+
+    /// Return the sequence of indices from the root down to `index` (inclusive),
+    /// built by walking parent links upward and reversing them. This lets a
+    /// prediction be attributed to the sequence of split decisions that
+    /// produced it.
+    /// # Example
+    /// ```
+    /// use gbdt::binary_tree::{BinaryTree, BinaryTreeNode};
+    /// let mut tree: BinaryTree<i32> = BinaryTree::new();
+    /// let root_index = tree.add_root(BinaryTreeNode::new(1));
+    /// let left_index = tree.add_left_node(root_index, BinaryTreeNode::new(2));
+    /// let leaf_index = tree.add_left_node(left_index, BinaryTreeNode::new(3));
+    /// assert_eq!(tree.path_to_root(leaf_index), vec![root_index, left_index, leaf_index]);
+    /// ```
+    pub fn path_to_root(&self, index: TreeIndex) -> Vec<TreeIndex> {
+        let mut path = Vec::new();
+        let mut current = index;
+        while let Some(node) = self.tree.get(current) {
+            path.push(current);
+            if current == self.get_root_index() {
+                break;
+            }
+            current = node.parent;
+        }
+        path.reverse();
+        path
+    }
+
     /// Add a node as the left child of a given `parent` node. Return the index of the added node.
     /// # Example
     ///
@@ -235,9 +326,18 @@ impl<T> BinaryTree<T> {
         is_left: bool,
         mut child: BinaryTreeNode<T>,
     ) -> TreeIndex {
-        child.index = self.tree.len();
-        self.tree.push(child);
-        let position = self.tree.len() - 1;
+        let position = if let Some(reused) = self.free.pop() {
+            reused
+        } else {
+            self.tree.len()
+        };
+        child.index = position;
+        child.parent = if position == 0 { 0 } else { parent };
+        if position == self.tree.len() {
+            self.tree.push(child);
+        } else {
+            self.tree[position] = child;
+        }
 
         if position == 0 {
             return position;
@@ -252,6 +352,96 @@ impl<T> BinaryTree<T> {
         position
     }
 
+    /// Return true when `index` refers to a node that is actually part of the
+    /// tree, as opposed to an empty slot or an already-reclaimed one. A freed
+    /// slot still sits in `tree` with its fields reset to 0, so `get_node`
+    /// alone can't tell a live node from a dead one; a dead slot's `index`
+    /// field no longer matches its position.
+    fn is_live(&self, index: TreeIndex) -> bool {
+        match self.tree.get(index) {
+            Some(node) => index == self.get_root_index() || node.index == index,
+            None => false,
+        }
+    }
+
+    /// Detach the subtree rooted at `index` from its parent and reclaim every
+    /// node in it, returning the number of nodes removed.
+    ///
+    /// Removing the root (index 0) clears the whole tree instead, since index
+    /// 0 also serves as the "no child" sentinel and can never be freed on its
+    /// own. Reclaimed slots are tracked in `free` and reused by `add_node`, so
+    /// their `left`/`right`/`index` fields are reset here to make sure stale
+    /// links can't resurface once the slot is reused. Calling this again on an
+    /// index that was already removed (or that was never a live node) is a
+    /// no-op that returns 0, rather than freeing the slot a second time.
+    /// # Example
+    /// ```
+    /// use gbdt::binary_tree::{BinaryTree, BinaryTreeNode};
+    /// let mut tree: BinaryTree<i32> = BinaryTree::new();
+    /// let root_index = tree.add_root(BinaryTreeNode::new(1));
+    /// let left_index = tree.add_left_node(root_index, BinaryTreeNode::new(2));
+    /// tree.add_left_node(left_index, BinaryTreeNode::new(3));
+    /// tree.add_right_node(root_index, BinaryTreeNode::new(4));
+    ///
+    /// let removed = tree.remove_subtree(left_index);
+    /// assert_eq!(removed, 2);
+    /// let root = tree.get_node(root_index).unwrap();
+    /// assert!(tree.get_left_child(root).is_none());
+    /// ```
+    pub fn remove_subtree(&mut self, index: TreeIndex) -> usize {
+        if !self.is_live(index) {
+            return 0;
+        }
+        if index == self.get_root_index() {
+            let removed = self.tree.len() - self.free.len();
+            self.tree.clear();
+            self.free.clear();
+            return removed;
+        }
+
+        let parent = self.tree[index].parent;
+        if let Some(n) = self.tree.get_mut(parent) {
+            if n.left == index {
+                n.left = 0;
+            }
+            if n.right == index {
+                n.right = 0;
+            }
+        }
+
+        self.reclaim_subtree(index)
+    }
+
+    /// Walk `index` and all its descendants, resetting each node and pushing
+    /// its slot onto `free` (index 0 excepted), returning the count removed.
+    fn reclaim_subtree(&mut self, index: TreeIndex) -> usize {
+        let mut count = 0;
+        let mut stack = vec![index];
+        while let Some(current) = stack.pop() {
+            let (left, right) = match self.tree.get(current) {
+                Some(node) => (node.left, node.right),
+                None => continue,
+            };
+            if left != 0 {
+                stack.push(left);
+            }
+            if right != 0 {
+                stack.push(right);
+            }
+            if let Some(node) = self.tree.get_mut(current) {
+                node.index = 0;
+                node.left = 0;
+                node.right = 0;
+                node.parent = 0;
+            }
+            if current != 0 {
+                self.free.push(current);
+            }
+            count += 1;
+        }
+        count
+    }
+
     /// For debug use. This API will print the whole tree.
     /// # Example
     /// ```
@@ -310,7 +500,10 @@ impl<T> BinaryTree<T> {
         }
     }
 
-    /// Get the amount of the nodes in this tree.
+    /// Get the amount of the live nodes in this tree, i.e. excluding any
+    /// slots reclaimed by [`remove_subtree`] and not yet reused.
+    ///
+    /// [`remove_subtree`]: #method.remove_subtree
     /// # Example
     /// ```
     /// use gbdt::binary_tree::{BinaryTree, BinaryTreeNode};
@@ -328,7 +521,440 @@ impl<T> BinaryTree<T> {
     /// assert_eq!(3, tree.len());
     /// ```
     pub fn len(&self) -> usize {
-        self.tree.len()
+        self.tree.len() - self.free.len()
+    }
+
+    /// Return an iterator that walks the tree pre-order (node, left, right).
+    ///
+    /// The tree is flat-vector backed, so this is implemented with an explicit
+    /// stack rather than recursion: the right child is pushed before the left
+    /// child so that the left subtree is popped and visited first.
+    /// # Example
+    /// ```
+    /// use gbdt::binary_tree::{BinaryTree, BinaryTreeNode};
+    /// let mut tree: BinaryTree<i32> = BinaryTree::new();
+    /// let root_index = tree.add_root(BinaryTreeNode::new(1));
+    /// let left_index = tree.add_left_node(root_index, BinaryTreeNode::new(2));
+    /// tree.add_right_node(root_index, BinaryTreeNode::new(3));
+    /// tree.add_left_node(left_index, BinaryTreeNode::new(4));
+    /// let values: Vec<i32> = tree.pre_order_iter().map(|n| n.value).collect();
+    /// assert_eq!(values, vec![1, 2, 4, 3]);
+    /// ```
+    pub fn pre_order_iter(&self) -> PreOrderIter<'_, T> {
+        let mut stack = Vec::new();
+        if !self.is_empty() {
+            stack.push(self.get_root_index());
+        }
+        PreOrderIter { tree: self, stack }
+    }
+
+    /// Return an iterator that walks the tree in-order (left, node, right).
+    ///
+    /// Implemented by walking down the left spine while recording pending
+    /// nodes on a stack, visiting a node when it is popped and then
+    /// descending into its right child.
+    /// # Example
+    /// ```
+    /// use gbdt::binary_tree::{BinaryTree, BinaryTreeNode};
+    /// let mut tree: BinaryTree<i32> = BinaryTree::new();
+    /// let root_index = tree.add_root(BinaryTreeNode::new(1));
+    /// let left_index = tree.add_left_node(root_index, BinaryTreeNode::new(2));
+    /// tree.add_right_node(root_index, BinaryTreeNode::new(3));
+    /// tree.add_left_node(left_index, BinaryTreeNode::new(4));
+    /// let values: Vec<i32> = tree.in_order_iter().map(|n| n.value).collect();
+    /// assert_eq!(values, vec![4, 2, 1, 3]);
+    /// ```
+    pub fn in_order_iter(&self) -> InOrderIter<'_, T> {
+        let current = if self.is_empty() {
+            None
+        } else {
+            Some(self.get_root_index())
+        };
+        InOrderIter {
+            tree: self,
+            stack: Vec::new(),
+            current,
+        }
+    }
+
+    /// Return an iterator that walks the tree post-order (left, right, node).
+    ///
+    /// Uses the two-stack method: the root is pushed to the first stack, then
+    /// repeatedly popped and pushed to a second stack (pushing its left child
+    /// then its right child onto the first stack). Draining the second stack
+    /// yields the post-order sequence.
+    /// # Example
+    /// ```
+    /// use gbdt::binary_tree::{BinaryTree, BinaryTreeNode};
+    /// let mut tree: BinaryTree<i32> = BinaryTree::new();
+    /// let root_index = tree.add_root(BinaryTreeNode::new(1));
+    /// let left_index = tree.add_left_node(root_index, BinaryTreeNode::new(2));
+    /// tree.add_right_node(root_index, BinaryTreeNode::new(3));
+    /// tree.add_left_node(left_index, BinaryTreeNode::new(4));
+    /// let values: Vec<i32> = tree.post_order_iter().map(|n| n.value).collect();
+    /// assert_eq!(values, vec![4, 2, 3, 1]);
+    /// ```
+    pub fn post_order_iter(&self) -> PostOrderIter<'_, T> {
+        let mut order = Vec::new();
+        if !self.is_empty() {
+            let mut stack_a = vec![self.get_root_index()];
+            let mut stack_b = Vec::new();
+            while let Some(index) = stack_a.pop() {
+                if let Some(node) = self.get_node(index) {
+                    stack_b.push(index);
+                    if node.left != 0 {
+                        stack_a.push(node.left);
+                    }
+                    if node.right != 0 {
+                        stack_a.push(node.right);
+                    }
+                }
+            }
+            order = stack_b;
+            order.reverse();
+        }
+        PostOrderIter {
+            tree: self,
+            order: order.into_iter(),
+        }
+    }
+
+    /// Return the indices of the tree in pre-order, without borrowing the tree.
+    /// Use this together with [`get_node_mut`] to mutate nodes while visiting
+    /// them, since an iterator borrowing `self` would conflict with a mutable
+    /// borrow of the same tree.
+    ///
+    /// [`get_node_mut`]: #method.get_node_mut
+    pub fn pre_order_iter_mut(&self) -> std::vec::IntoIter<TreeIndex> {
+        let indices: Vec<TreeIndex> = self.pre_order_iter().map(|n| n.index).collect();
+        indices.into_iter()
+    }
+
+    /// Return the indices of the tree in-order, without borrowing the tree.
+    /// See [`pre_order_iter_mut`] for why this yields indices instead of
+    /// references.
+    ///
+    /// [`pre_order_iter_mut`]: #method.pre_order_iter_mut
+    pub fn in_order_iter_mut(&self) -> std::vec::IntoIter<TreeIndex> {
+        let indices: Vec<TreeIndex> = self.in_order_iter().map(|n| n.index).collect();
+        indices.into_iter()
+    }
+
+    /// Return the indices of the tree post-order, without borrowing the tree.
+    /// See [`pre_order_iter_mut`] for why this yields indices instead of
+    /// references.
+    ///
+    /// [`pre_order_iter_mut`]: #method.pre_order_iter_mut
+    pub fn post_order_iter_mut(&self) -> std::vec::IntoIter<TreeIndex> {
+        let indices: Vec<TreeIndex> = self.post_order_iter().map(|n| n.index).collect();
+        indices.into_iter()
+    }
+
+    /// Return an iterator that walks the tree level by level (breadth-first),
+    /// backed by a `VecDeque` rather than recursion.
+    /// # Example
+    /// ```
+    /// use gbdt::binary_tree::{BinaryTree, BinaryTreeNode};
+    /// let mut tree: BinaryTree<i32> = BinaryTree::new();
+    /// let root_index = tree.add_root(BinaryTreeNode::new(1));
+    /// let left_index = tree.add_left_node(root_index, BinaryTreeNode::new(2));
+    /// tree.add_right_node(root_index, BinaryTreeNode::new(3));
+    /// tree.add_left_node(left_index, BinaryTreeNode::new(4));
+    /// let values: Vec<i32> = tree.level_order_iter().map(|n| n.value).collect();
+    /// assert_eq!(values, vec![1, 2, 3, 4]);
+    /// ```
+    pub fn level_order_iter(&self) -> LevelOrderIter<'_, T> {
+        let mut queue = std::collections::VecDeque::new();
+        if !self.is_empty() {
+            queue.push_back(self.get_root_index());
+        }
+        LevelOrderIter { tree: self, queue }
+    }
+
+    /// Return the height (in nodes) of the subtree rooted at every node,
+    /// keyed by node index, computed bottom-up from a post-order walk so that
+    /// descendants already have an entry before their ancestors are visited.
+    fn subtree_heights(&self) -> std::collections::HashMap<TreeIndex, usize> {
+        let mut heights = std::collections::HashMap::new();
+        for node in self.post_order_iter() {
+            let left_height = if node.left != 0 {
+                *heights.get(&node.left).unwrap_or(&0)
+            } else {
+                0
+            };
+            let right_height = if node.right != 0 {
+                *heights.get(&node.right).unwrap_or(&0)
+            } else {
+                0
+            };
+            heights.insert(node.index, 1 + left_height.max(right_height));
+        }
+        heights
+    }
+
+    /// Return the depth (in nodes) of the longest root-to-leaf path, or 0 for
+    /// an empty tree. Implemented with a `(TreeIndex, depth)` BFS queue rather
+    /// than recursion, tracking the deepest leaf seen.
+    /// # Example
+    /// ```
+    /// use gbdt::binary_tree::{BinaryTree, BinaryTreeNode};
+    /// let mut tree: BinaryTree<i32> = BinaryTree::new();
+    /// let root_index = tree.add_root(BinaryTreeNode::new(1));
+    /// let left_index = tree.add_left_node(root_index, BinaryTreeNode::new(2));
+    /// tree.add_left_node(left_index, BinaryTreeNode::new(3));
+    /// assert_eq!(tree.max_depth(), 3);
+    /// ```
+    pub fn max_depth(&self) -> usize {
+        self.leaf_depth_bounds().map_or(0, |(_, max)| max)
+    }
+
+    /// Return the depth (in nodes) of the nearest root-to-leaf path, or 0 for
+    /// an empty tree. Implemented the same way as [`max_depth`].
+    ///
+    /// [`max_depth`]: #method.max_depth
+    /// # Example
+    /// ```
+    /// use gbdt::binary_tree::{BinaryTree, BinaryTreeNode};
+    /// let mut tree: BinaryTree<i32> = BinaryTree::new();
+    /// let root_index = tree.add_root(BinaryTreeNode::new(1));
+    /// let left_index = tree.add_left_node(root_index, BinaryTreeNode::new(2));
+    /// tree.add_right_node(root_index, BinaryTreeNode::new(3));
+    /// tree.add_left_node(left_index, BinaryTreeNode::new(4));
+    /// assert_eq!(tree.min_depth(), 2);
+    /// ```
+    pub fn min_depth(&self) -> usize {
+        self.leaf_depth_bounds().map_or(0, |(min, _)| min)
+    }
+
+    /// Walk the tree breadth-first carrying `(TreeIndex, depth)` pairs and
+    /// return the `(min, max)` depth observed among leaves (nodes whose
+    /// `left` and `right` are both 0). Returns `None` for an empty tree.
+    fn leaf_depth_bounds(&self) -> Option<(usize, usize)> {
+        if self.is_empty() {
+            return None;
+        }
+        let mut queue = std::collections::VecDeque::new();
+        queue.push_back((self.get_root_index(), 1));
+        let mut bounds: Option<(usize, usize)> = None;
+        while let Some((index, depth)) = queue.pop_front() {
+            let node = match self.get_node(index) {
+                Some(node) => node,
+                None => continue,
+            };
+            if node.left == 0 && node.right == 0 {
+                bounds = Some(match bounds {
+                    Some((min, max)) => (min.min(depth), max.max(depth)),
+                    None => (depth, depth),
+                });
+                continue;
+            }
+            if node.left != 0 {
+                queue.push_back((node.left, depth + 1));
+            }
+            if node.right != 0 {
+                queue.push_back((node.right, depth + 1));
+            }
+        }
+        bounds
+    }
+
+    /// Return true when, at every node, the heights of the left and right
+    /// subtrees differ by at most one.
+    /// # Example
+    /// ```
+    /// use gbdt::binary_tree::{BinaryTree, BinaryTreeNode};
+    /// let mut tree: BinaryTree<i32> = BinaryTree::new();
+    /// let root_index = tree.add_root(BinaryTreeNode::new(1));
+    /// let left_index = tree.add_left_node(root_index, BinaryTreeNode::new(2));
+    /// tree.add_right_node(root_index, BinaryTreeNode::new(3));
+    /// let leaf_index = tree.add_left_node(left_index, BinaryTreeNode::new(4));
+    /// tree.add_left_node(leaf_index, BinaryTreeNode::new(5));
+    /// assert!(!tree.is_balanced());
+    /// ```
+    pub fn is_balanced(&self) -> bool {
+        let heights = self.subtree_heights();
+        self.pre_order_iter().all(|node| {
+            let left_height = if node.left != 0 {
+                *heights.get(&node.left).unwrap_or(&0)
+            } else {
+                0
+            };
+            let right_height = if node.right != 0 {
+                *heights.get(&node.right).unwrap_or(&0)
+            } else {
+                0
+            };
+            (left_height as isize - right_height as isize).abs() <= 1
+        })
+    }
+
+    /// Return the tree's nodes in breadth-first order. This is the canonical,
+    /// gap-free layout produced after a sequence of additions and removals
+    /// may have left the backing `Vec` fragmented; pair with [`from_flat`] to
+    /// rebuild a dense tree from it.
+    ///
+    /// [`from_flat`]: #method.from_flat
+    /// # Example
+    /// ```
+    /// use gbdt::binary_tree::{BinaryTree, BinaryTreeNode};
+    /// let mut tree: BinaryTree<i32> = BinaryTree::new();
+    /// let root_index = tree.add_root(BinaryTreeNode::new(1));
+    /// tree.add_left_node(root_index, BinaryTreeNode::new(2));
+    /// tree.add_right_node(root_index, BinaryTreeNode::new(3));
+    /// let values: Vec<i32> = tree.flatten().iter().map(|n| n.value).collect();
+    /// assert_eq!(values, vec![1, 2, 3]);
+    /// ```
+    pub fn flatten(&self) -> Vec<&BinaryTreeNode<T>> {
+        self.level_order_iter().collect()
+    }
+
+    /// Rebuild a dense, hole-free `BinaryTree` from nodes in breadth-first
+    /// order (as produced by [`flatten`]), renumbering every index so the
+    /// result has no gaps and the root stays at index 0. Returns an empty
+    /// tree for an empty input.
+    ///
+    /// [`flatten`]: #method.flatten
+    /// # Example
+    /// ```
+    /// use gbdt::binary_tree::{BinaryTree, BinaryTreeNode};
+    /// let mut tree: BinaryTree<i32> = BinaryTree::new();
+    /// let root_index = tree.add_root(BinaryTreeNode::new(1));
+    /// let left_index = tree.add_left_node(root_index, BinaryTreeNode::new(2));
+    /// tree.add_right_node(root_index, BinaryTreeNode::new(3));
+    /// tree.remove_subtree(left_index);
+    /// tree.add_left_node(root_index, BinaryTreeNode::new(4));
+    ///
+    /// let flat: Vec<BinaryTreeNode<i32>> =
+    ///     tree.flatten().into_iter().cloned().collect();
+    /// let rebuilt = BinaryTree::from_flat(flat);
+    /// assert_eq!(rebuilt.len(), 3);
+    /// let values: Vec<i32> = rebuilt.level_order_iter().map(|n| n.value).collect();
+    /// assert_eq!(values, vec![1, 4, 3]);
+    /// ```
+    pub fn from_flat(nodes: Vec<BinaryTreeNode<T>>) -> BinaryTree<T> {
+        if nodes.is_empty() {
+            return BinaryTree::new();
+        }
+
+        let mut old_to_new = std::collections::HashMap::with_capacity(nodes.len());
+        for (new_index, node) in nodes.iter().enumerate() {
+            old_to_new.insert(node.index, new_index);
+        }
+        let remap = |old: TreeIndex| -> TreeIndex { *old_to_new.get(&old).unwrap_or(&0) };
+
+        let tree: Vec<BinaryTreeNode<T>> = nodes
+            .into_iter()
+            .enumerate()
+            .map(|(new_index, mut node)| {
+                node.left = remap(node.left);
+                node.right = remap(node.right);
+                node.parent = remap(node.parent);
+                node.index = new_index;
+                node
+            })
+            .collect();
+        BinaryTree {
+            tree,
+            free: Vec::new(),
+        }
+    }
+}
+
+/// Iterator over a [`BinaryTree`] in pre-order. See [`BinaryTree::pre_order_iter`].
+///
+/// [`BinaryTree`]: struct.BinaryTree.html
+/// [`BinaryTree::pre_order_iter`]: struct.BinaryTree.html#method.pre_order_iter
+pub struct PreOrderIter<'a, T> {
+    tree: &'a BinaryTree<T>,
+    stack: Vec<TreeIndex>,
+}
+
+impl<'a, T> Iterator for PreOrderIter<'a, T> {
+    type Item = &'a BinaryTreeNode<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.stack.pop()?;
+        let node = self.tree.get_node(index)?;
+        if node.right != 0 {
+            self.stack.push(node.right);
+        }
+        if node.left != 0 {
+            self.stack.push(node.left);
+        }
+        Some(node)
+    }
+}
+
+/// Iterator over a [`BinaryTree`] in-order. See [`BinaryTree::in_order_iter`].
+///
+/// [`BinaryTree`]: struct.BinaryTree.html
+/// [`BinaryTree::in_order_iter`]: struct.BinaryTree.html#method.in_order_iter
+pub struct InOrderIter<'a, T> {
+    tree: &'a BinaryTree<T>,
+    stack: Vec<TreeIndex>,
+    current: Option<TreeIndex>,
+}
+
+impl<'a, T> Iterator for InOrderIter<'a, T> {
+    type Item = &'a BinaryTreeNode<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(index) = self.current {
+            self.stack.push(index);
+            let node = self.tree.get_node(index)?;
+            self.current = if node.left != 0 { Some(node.left) } else { None };
+        }
+        let index = self.stack.pop()?;
+        let node = self.tree.get_node(index)?;
+        self.current = if node.right != 0 {
+            Some(node.right)
+        } else {
+            None
+        };
+        Some(node)
+    }
+}
+
+/// Iterator over a [`BinaryTree`] post-order. See [`BinaryTree::post_order_iter`].
+///
+/// [`BinaryTree`]: struct.BinaryTree.html
+/// [`BinaryTree::post_order_iter`]: struct.BinaryTree.html#method.post_order_iter
+pub struct PostOrderIter<'a, T> {
+    tree: &'a BinaryTree<T>,
+    order: std::vec::IntoIter<TreeIndex>,
+}
+
+impl<'a, T> Iterator for PostOrderIter<'a, T> {
+    type Item = &'a BinaryTreeNode<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.order.next()?;
+        self.tree.get_node(index)
+    }
+}
+
+/// Iterator over a [`BinaryTree`] level by level. See [`BinaryTree::level_order_iter`].
+///
+/// [`BinaryTree`]: struct.BinaryTree.html
+/// [`BinaryTree::level_order_iter`]: struct.BinaryTree.html#method.level_order_iter
+pub struct LevelOrderIter<'a, T> {
+    tree: &'a BinaryTree<T>,
+    queue: std::collections::VecDeque<TreeIndex>,
+}
+
+impl<'a, T> Iterator for LevelOrderIter<'a, T> {
+    type Item = &'a BinaryTreeNode<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.queue.pop_front()?;
+        let node = self.tree.get_node(index)?;
+        if node.left != 0 {
+            self.queue.push_back(node.left);
+        }
+        if node.right != 0 {
+            self.queue.push_back(node.right);
+        }
+        Some(node)
     }
 }
 
@@ -343,6 +969,7 @@ mod tests {
         assert_eq!(root.index, 0);
         assert_eq!(root.left, 0);
         assert_eq!(root.right, 0);
+        assert_eq!(root.parent, 0);
     }
 
     #[test]
@@ -493,4 +1120,278 @@ mod tests {
 
         assert_eq!(3, tree.len());
     }
+
+    fn build_sample_tree() -> BinaryTree<i32> {
+        //       1
+        //      / \
+        //     2   3
+        //    / \
+        //   4   5
+        let mut tree: BinaryTree<i32> = BinaryTree::new();
+        let root_index = tree.add_root(BinaryTreeNode::new(1));
+        let left_index = tree.add_left_node(root_index, BinaryTreeNode::new(2));
+        tree.add_right_node(root_index, BinaryTreeNode::new(3));
+        tree.add_left_node(left_index, BinaryTreeNode::new(4));
+        tree.add_right_node(left_index, BinaryTreeNode::new(5));
+        tree
+    }
+
+    #[test]
+    fn pre_order_iter_visits_node_then_left_then_right() {
+        let tree = build_sample_tree();
+        let values: Vec<i32> = tree.pre_order_iter().map(|n| n.value).collect();
+        assert_eq!(values, vec![1, 2, 4, 5, 3]);
+    }
+
+    #[test]
+    fn in_order_iter_visits_left_then_node_then_right() {
+        let tree = build_sample_tree();
+        let values: Vec<i32> = tree.in_order_iter().map(|n| n.value).collect();
+        assert_eq!(values, vec![4, 2, 5, 1, 3]);
+    }
+
+    #[test]
+    fn post_order_iter_visits_left_then_right_then_node() {
+        let tree = build_sample_tree();
+        let values: Vec<i32> = tree.post_order_iter().map(|n| n.value).collect();
+        assert_eq!(values, vec![4, 5, 2, 3, 1]);
+    }
+
+    #[test]
+    fn traversal_iters_on_empty_tree_yield_nothing() {
+        let tree: BinaryTree<i32> = BinaryTree::new();
+        assert_eq!(tree.pre_order_iter().count(), 0);
+        assert_eq!(tree.in_order_iter().count(), 0);
+        assert_eq!(tree.post_order_iter().count(), 0);
+    }
+
+    #[test]
+    fn mut_traversal_iters_yield_indices_for_mutation() {
+        let mut tree = build_sample_tree();
+        for index in tree.pre_order_iter_mut() {
+            let node = tree.get_node_mut(index).unwrap();
+            node.value *= 10;
+        }
+        let values: Vec<i32> = tree.in_order_iter().map(|n| n.value).collect();
+        assert_eq!(values, vec![40, 20, 50, 10, 30]);
+    }
+
+    #[test]
+    fn remove_subtree_detaches_from_parent_and_counts_removed() {
+        let mut tree = build_sample_tree();
+        let left_index = tree
+            .in_order_iter()
+            .find(|n| n.value == 2)
+            .map(|n| n.index)
+            .unwrap();
+
+        let removed = tree.remove_subtree(left_index);
+        assert_eq!(removed, 3);
+
+        let root = tree.get_node(tree.get_root_index()).unwrap();
+        assert!(tree.get_left_child(root).is_none());
+        let values: Vec<i32> = tree.in_order_iter().map(|n| n.value).collect();
+        assert_eq!(values, vec![1, 3]);
+    }
+
+    #[test]
+    fn remove_subtree_on_root_clears_whole_tree() {
+        let mut tree = build_sample_tree();
+        let removed = tree.remove_subtree(tree.get_root_index());
+        assert_eq!(removed, 5);
+        assert!(tree.is_empty());
+    }
+
+    #[test]
+    fn remove_subtree_reuses_freed_slots() {
+        let mut tree = build_sample_tree();
+        let left_index = tree
+            .in_order_iter()
+            .find(|n| n.value == 2)
+            .map(|n| n.index)
+            .unwrap();
+        tree.remove_subtree(left_index);
+
+        let root_index = tree.get_root_index();
+        let len_before = tree.len();
+        let vec_len_before = tree.tree.len();
+        let new_index = tree.add_left_node(root_index, BinaryTreeNode::new(6));
+        assert_eq!(tree.len(), len_before + 1);
+        assert_eq!(
+            tree.tree.len(),
+            vec_len_before,
+            "reused slot should not grow the vec"
+        );
+        assert_eq!(tree.get_node(new_index).unwrap().value, 6);
+
+        let root = tree.get_node(root_index).unwrap();
+        let left_node = tree.get_left_child(root).unwrap();
+        assert!(tree.get_left_child(left_node).is_none());
+        assert!(tree.get_right_child(left_node).is_none());
+    }
+
+    #[test]
+    fn remove_subtree_twice_on_same_index_is_a_no_op_the_second_time() {
+        let mut tree: BinaryTree<i32> = BinaryTree::new();
+        let root_index = tree.add_root(BinaryTreeNode::new(1));
+        let left_index = tree.add_left_node(root_index, BinaryTreeNode::new(2));
+        let right_index = tree.add_right_node(root_index, BinaryTreeNode::new(3));
+
+        assert_eq!(tree.remove_subtree(left_index), 1);
+        assert_eq!(
+            tree.remove_subtree(left_index),
+            0,
+            "removing an already-reclaimed slot must not free it again"
+        );
+
+        let first_new = tree.add_left_node(right_index, BinaryTreeNode::new(6));
+        let second_new = tree.add_right_node(right_index, BinaryTreeNode::new(7));
+        assert_ne!(
+            first_new, second_new,
+            "two live nodes must not collide in the same slot"
+        );
+        assert_eq!(tree.get_node(first_new).unwrap().value, 6);
+        assert_eq!(tree.get_node(second_new).unwrap().value, 7);
+    }
+
+    #[test]
+    fn get_parent_returns_none_for_root() {
+        let tree = build_sample_tree();
+        let root = tree.get_node(tree.get_root_index()).unwrap();
+        assert!(tree.get_parent(root).is_none());
+    }
+
+    #[test]
+    fn get_parent_returns_parent_for_non_root() {
+        let tree = build_sample_tree();
+        let left_index = tree
+            .in_order_iter()
+            .find(|n| n.value == 4)
+            .map(|n| n.index)
+            .unwrap();
+        let node = tree.get_node(left_index).unwrap();
+        let parent = tree.get_parent(node).unwrap();
+        assert_eq!(parent.value, 2);
+    }
+
+    #[test]
+    fn path_to_root_walks_parent_links_from_root_down() {
+        let tree = build_sample_tree();
+        let root_index = tree.get_root_index();
+        let left_index = tree
+            .in_order_iter()
+            .find(|n| n.value == 2)
+            .map(|n| n.index)
+            .unwrap();
+        let leaf_index = tree
+            .in_order_iter()
+            .find(|n| n.value == 4)
+            .map(|n| n.index)
+            .unwrap();
+        assert_eq!(
+            tree.path_to_root(leaf_index),
+            vec![root_index, left_index, leaf_index]
+        );
+        assert_eq!(tree.path_to_root(root_index), vec![root_index]);
+    }
+
+    #[test]
+    fn level_order_iter_visits_nodes_by_level() {
+        let tree = build_sample_tree();
+        let values: Vec<i32> = tree.level_order_iter().map(|n| n.value).collect();
+        assert_eq!(values, vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn level_order_iter_on_empty_tree_yields_nothing() {
+        let tree: BinaryTree<i32> = BinaryTree::new();
+        assert_eq!(tree.level_order_iter().count(), 0);
+    }
+
+    #[test]
+    fn max_depth_and_min_depth_on_sample_tree() {
+        let tree = build_sample_tree();
+        assert_eq!(tree.max_depth(), 3);
+        assert_eq!(tree.min_depth(), 2);
+    }
+
+    #[test]
+    fn depth_on_empty_tree_is_zero() {
+        let tree: BinaryTree<i32> = BinaryTree::new();
+        assert_eq!(tree.max_depth(), 0);
+        assert_eq!(tree.min_depth(), 0);
+    }
+
+    #[test]
+    fn depth_on_single_node_tree_is_one() {
+        let mut tree: BinaryTree<i32> = BinaryTree::new();
+        tree.add_root(BinaryTreeNode::new(1));
+        assert_eq!(tree.max_depth(), 1);
+        assert_eq!(tree.min_depth(), 1);
+    }
+
+    #[test]
+    fn is_balanced_true_for_sample_tree() {
+        let tree = build_sample_tree();
+        assert!(tree.is_balanced());
+    }
+
+    #[test]
+    fn is_balanced_false_for_skewed_tree() {
+        let mut tree: BinaryTree<i32> = BinaryTree::new();
+        let root_index = tree.add_root(BinaryTreeNode::new(1));
+        let n1 = tree.add_left_node(root_index, BinaryTreeNode::new(2));
+        let n2 = tree.add_left_node(n1, BinaryTreeNode::new(3));
+        tree.add_left_node(n2, BinaryTreeNode::new(4));
+        assert!(!tree.is_balanced());
+    }
+
+    #[test]
+    fn flatten_returns_nodes_in_breadth_first_order() {
+        let tree = build_sample_tree();
+        let values: Vec<i32> = tree.flatten().into_iter().map(|n| n.value).collect();
+        assert_eq!(values, vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn from_flat_round_trips_a_dense_tree() {
+        let tree = build_sample_tree();
+        let flat: Vec<BinaryTreeNode<i32>> = tree.flatten().into_iter().cloned().collect();
+        let rebuilt = BinaryTree::from_flat(flat);
+
+        assert_eq!(rebuilt.len(), tree.len());
+        let values: Vec<i32> = rebuilt.level_order_iter().map(|n| n.value).collect();
+        assert_eq!(values, vec![1, 2, 3, 4, 5]);
+
+        let root = rebuilt.get_node(rebuilt.get_root_index()).unwrap();
+        let left = rebuilt.get_left_child(root).unwrap();
+        let leaf = rebuilt.get_left_child(left).unwrap();
+        assert_eq!(leaf.value, 4);
+        assert_eq!(rebuilt.get_parent(leaf).unwrap().value, 2);
+    }
+
+    #[test]
+    fn from_flat_renumbers_away_fragmentation() {
+        let mut tree = build_sample_tree();
+        let left_index = tree
+            .in_order_iter()
+            .find(|n| n.value == 2)
+            .map(|n| n.index)
+            .unwrap();
+        tree.remove_subtree(left_index);
+        tree.add_left_node(tree.get_root_index(), BinaryTreeNode::new(6));
+
+        let flat: Vec<BinaryTreeNode<i32>> = tree.flatten().into_iter().cloned().collect();
+        let rebuilt = BinaryTree::from_flat(flat);
+
+        assert_eq!(rebuilt.len(), 3);
+        let values: Vec<i32> = rebuilt.level_order_iter().map(|n| n.value).collect();
+        assert_eq!(values, vec![1, 6, 3]);
+    }
+
+    #[test]
+    fn from_flat_on_empty_input_is_empty() {
+        let rebuilt: BinaryTree<i32> = BinaryTree::from_flat(Vec::new());
+        assert!(rebuilt.is_empty());
+    }
 }